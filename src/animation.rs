@@ -0,0 +1,563 @@
+//! Time-based interpolation of values.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Easing curve applied to the progress of an animation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Start slow and accelerate.
+    EaseIn,
+    /// Start fast and decelerate.
+    EaseOut,
+    /// Accelerate in the first half and decelerate in the second.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map a linear progress in [0, 1] onto the curve.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+        }
+    }
+}
+
+/// What an animation does once it reaches its target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Stop at the target.
+    #[default]
+    Once,
+    /// Restart from the start value and move towards the same target again.
+    Repeat,
+    /// Swap start and target and move back.
+    PingPong,
+}
+
+/// Values that can be linearly interpolated.
+pub trait Interpolate: Copy {
+    /// Value at `t` between `self` (t = 0) and `other` (t = 1).
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<const N: usize> Interpolate for [f32; N] {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&other[i], t))
+    }
+}
+
+/// A value that moves towards its target over a fixed duration.
+#[derive(Debug, Clone)]
+pub struct Animated<T: Interpolate> {
+    /// Value at the moment the current target was set.
+    start: T,
+    /// Value at the current point of the animation.
+    current: T,
+    /// Value reached at the end of the animation.
+    target: T,
+    /// Total length of the animation.
+    duration: Duration,
+    /// Time elapsed since the current target was set.
+    elapsed_time: Duration,
+    /// Set once `elapsed_time` reaches `duration`, or when the value is snapped.
+    finished: bool,
+    /// Curve used to interpolate between start and target.
+    easing: Easing,
+    /// Behaviour at the end of the animation.
+    loop_mode: LoopMode,
+}
+
+impl<T: Interpolate> Animated<T> {
+    /// Create a new animated value resting at `value`.
+    pub fn new(value: T, duration: Duration, easing: Easing) -> Self {
+        Self {
+            start: value,
+            current: value,
+            target: value,
+            duration,
+            elapsed_time: Duration::ZERO,
+            finished: true,
+            easing,
+            loop_mode: LoopMode::Once,
+        }
+    }
+
+    /// Current value of the animation.
+    pub fn current(&self) -> T {
+        self.current
+    }
+
+    /// Value the animation is moving towards.
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// Start animating from the current value towards `target`.
+    pub fn set_target(&mut self, target: T) {
+        self.start = self.current;
+        self.target = target;
+        self.elapsed_time = Duration::ZERO;
+        self.finished = false;
+    }
+
+    /// Jump straight to `value` without animating.
+    pub fn set_current(&mut self, value: T) {
+        self.start = value;
+        self.current = value;
+        self.target = value;
+        self.elapsed_time = Duration::ZERO;
+        self.finished = true;
+    }
+
+    /// Set what happens when the animation reaches its target.
+    pub fn set_loop(&mut self, loop_mode: LoopMode) {
+        self.loop_mode = loop_mode;
+    }
+
+    /// Whether the animation has run for its whole duration.
+    /// Looping animations never complete.
+    pub fn complete(&self) -> bool {
+        self.loop_mode == LoopMode::Once && self.finished
+    }
+
+    /// Advance the animation by `delta`.
+    /// Time past the end of a loop carries over into the next one.
+    pub fn update(&mut self, delta: Duration) {
+        if self.complete() {
+            return;
+        }
+
+        self.elapsed_time += delta;
+        if self.elapsed_time >= self.duration {
+            if self.loop_mode == LoopMode::Once {
+                self.elapsed_time = self.duration;
+                self.current = self.target;
+                self.finished = true;
+                return;
+            }
+            if self.duration.is_zero() {
+                self.current = self.target;
+                return;
+            }
+
+            // Landing exactly on a loop boundary ends the current loop at its
+            // target instead of wrapping back to the start.
+            let duration = self.duration.as_nanos();
+            let elapsed = self.elapsed_time.as_nanos();
+            let (mut loops, mut remainder) = (elapsed / duration, elapsed % duration);
+            if remainder == 0 {
+                loops -= 1;
+                remainder = duration;
+            }
+            if self.loop_mode == LoopMode::PingPong && loops % 2 == 1 {
+                std::mem::swap(&mut self.start, &mut self.target);
+            }
+            self.elapsed_time = Duration::from_nanos(remainder as u64);
+        }
+
+        let progress = self.elapsed_time.as_secs_f32() / self.duration.as_secs_f32();
+        self.current = self
+            .start
+            .interpolate(&self.target, self.easing.apply(progress));
+    }
+}
+
+/// Handle to a task scheduled on an [`AnimationManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TweenHandle(u64);
+
+/// One-shot animation of an `f32` value, run by an [`AnimationManager`].
+pub struct Tween {
+    /// Animated value.
+    value: Animated<f32>,
+    /// Time left before the tween starts.
+    delay: Duration,
+    /// Called with the new value after every update.
+    on_update: Option<Box<dyn FnMut(f32)>>,
+    /// Called once when the tween reaches its target.
+    on_complete: Option<Box<dyn FnOnce()>>,
+}
+
+impl Tween {
+    /// Create a tween from `from` to `to` over `duration`.
+    pub fn new(from: f32, to: f32, duration: Duration, easing: Easing) -> Self {
+        let mut value = Animated::new(from, duration, easing);
+        value.set_target(to);
+        Self {
+            value,
+            delay: Duration::ZERO,
+            on_update: None,
+            on_complete: None,
+        }
+    }
+
+    /// Wait for `delay` before starting the tween.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Register a callback receiving the value after every update.
+    pub fn on_update(mut self, callback: Box<dyn FnMut(f32)>) -> Self {
+        self.on_update = Some(callback);
+        self
+    }
+
+    /// Register a callback invoked when the tween completes.
+    pub fn on_complete(mut self, callback: Box<dyn FnOnce()>) -> Self {
+        self.on_complete = Some(callback);
+        self
+    }
+
+    /// Advance the tween by `delta`, counting down the delay first.
+    /// Returns the time left over past the end if the tween completes.
+    fn advance(&mut self, mut delta: Duration) -> Option<Duration> {
+        if !self.delay.is_zero() {
+            if delta < self.delay {
+                self.delay -= delta;
+                return None;
+            }
+            delta -= self.delay;
+            self.delay = Duration::ZERO;
+        }
+
+        let remaining = self.value.duration.saturating_sub(self.value.elapsed_time);
+        self.value.update(delta);
+        if let Some(on_update) = &mut self.on_update {
+            on_update(self.value.current());
+        }
+        if !self.value.complete() {
+            return None;
+        }
+
+        if let Some(on_complete) = self.on_complete.take() {
+            on_complete();
+        }
+        Some(delta.saturating_sub(remaining))
+    }
+}
+
+/// Scheduled tween along with its sequencing constraint.
+struct Task {
+    handle: TweenHandle,
+    tween: Tween,
+    /// Task that has to finish before this one starts.
+    waiting_on: Option<TweenHandle>,
+}
+
+/// Advances app-level tweens and timers independently of any widget.
+#[derive(Default)]
+pub struct AnimationManager {
+    /// Value of the next handle to hand out.
+    next_handle: u64,
+    /// Running and waiting tasks, in scheduling order.
+    tasks: Vec<Task>,
+}
+
+impl AnimationManager {
+    /// Create an empty manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start running `tween`.
+    pub fn schedule(&mut self, tween: Tween) -> TweenHandle {
+        self.push(tween, None)
+    }
+
+    /// Start running `tween` once the task `previous` has finished.
+    /// If `previous` is not running the tween starts right away.
+    pub fn schedule_after(&mut self, previous: TweenHandle, tween: Tween) -> TweenHandle {
+        self.push(tween, Some(previous))
+    }
+
+    /// Invoke `callback` once `delay` has elapsed.
+    /// This is a zero-length tween that only carries a delay.
+    pub fn after(&mut self, delay: Duration, callback: Box<dyn FnOnce()>) -> TweenHandle {
+        let timer = Tween::new(0.0, 0.0, Duration::ZERO, Easing::Linear)
+            .with_delay(delay)
+            .on_complete(callback);
+        self.schedule(timer)
+    }
+
+    /// Stop a task without firing its completion callback.
+    /// Returns false if the task was not running.
+    pub fn cancel(&mut self, handle: TweenHandle) -> bool {
+        let len = self.tasks.len();
+        self.tasks.retain(|task| task.handle != handle);
+        self.tasks.len() != len
+    }
+
+    /// Whether the task is still running or waiting to start.
+    pub fn is_active(&self, handle: TweenHandle) -> bool {
+        self.tasks.iter().any(|task| task.handle == handle)
+    }
+
+    /// Current value of a running tween.
+    pub fn value(&self, handle: TweenHandle) -> Option<f32> {
+        self.tasks
+            .iter()
+            .find(|task| task.handle == handle)
+            .map(|task| task.tween.value.current())
+    }
+
+    /// Advance all started tasks by `delta`, firing the callbacks of the ones that complete.
+    /// A task waiting on one that completes during this call starts right away with the
+    /// time left over past the end of its predecessor.
+    pub fn update(&mut self, delta: Duration) {
+        let active: HashSet<TweenHandle> = self.tasks.iter().map(|task| task.handle).collect();
+        let mut finished: HashMap<TweenHandle, Duration> = HashMap::new();
+
+        // Tasks are in scheduling order, so a predecessor is always handled before its waiters.
+        for task in &mut self.tasks {
+            let mut delta = delta;
+            if let Some(previous) = task.waiting_on {
+                if let Some(&leftover) = finished.get(&previous) {
+                    delta = leftover;
+                } else if active.contains(&previous) {
+                    continue;
+                }
+                task.waiting_on = None;
+            }
+
+            if let Some(leftover) = task.tween.advance(delta) {
+                finished.insert(task.handle, leftover);
+            }
+        }
+
+        self.tasks
+            .retain(|task| !finished.contains_key(&task.handle));
+    }
+
+    /// Store a new task and hand out its handle.
+    fn push(&mut self, tween: Tween, waiting_on: Option<TweenHandle>) -> TweenHandle {
+        let handle = TweenHandle(self.next_handle);
+        self.next_handle += 1;
+        self.tasks.push(Task {
+            handle,
+            tween,
+            waiting_on,
+        });
+        handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn moves_towards_target() {
+        let mut value = Animated::new(0.0, Duration::from_secs(1), Easing::Linear);
+        value.set_target(10.0);
+        assert!(!value.complete());
+
+        value.update(Duration::from_millis(500));
+        assert!((value.current() - 5.0).abs() < 1e-5);
+
+        value.update(Duration::from_millis(600));
+        assert_eq!(value.current(), 10.0);
+        assert!(value.complete());
+    }
+
+    #[test]
+    fn set_current_snaps_without_animating() {
+        let mut value = Animated::new([0.0, 0.0], Duration::from_secs(1), Easing::EaseOut);
+        value.set_target([4.0, 8.0]);
+        value.update(Duration::from_millis(250));
+
+        value.set_current([1.0, 2.0]);
+        assert_eq!(value.current(), [1.0, 2.0]);
+        assert_eq!(value.target(), [1.0, 2.0]);
+        assert!(value.complete());
+
+        value.update(Duration::from_millis(250));
+        assert_eq!(value.current(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn same_value_target_runs_for_duration() {
+        let mut value = Animated::new(1.0, Duration::from_secs(1), Easing::Linear);
+        value.set_target(1.0);
+        assert!(!value.complete());
+
+        value.update(Duration::from_millis(500));
+        assert!(!value.complete());
+        value.update(Duration::from_millis(500));
+        assert!(value.complete());
+    }
+
+    #[test]
+    fn repeat_carries_overshoot() {
+        let mut value = Animated::new(0.0, Duration::from_secs(1), Easing::Linear);
+        value.set_loop(LoopMode::Repeat);
+        value.set_target(10.0);
+
+        value.update(Duration::from_millis(1250));
+        assert!((value.current() - 2.5).abs() < 1e-5);
+        assert_eq!(value.target(), 10.0);
+        assert!(!value.complete());
+    }
+
+    #[test]
+    fn repeat_shows_target_on_loop_boundary() {
+        let mut value = Animated::new(0.0, Duration::from_secs(1), Easing::Linear);
+        value.set_loop(LoopMode::Repeat);
+        value.set_target(10.0);
+
+        value.update(Duration::from_secs(1));
+        assert_eq!(value.current(), 10.0);
+
+        value.update(Duration::from_millis(250));
+        assert!((value.current() - 2.5).abs() < 1e-5);
+
+        value.update(Duration::from_millis(1750));
+        assert_eq!(value.current(), 10.0);
+    }
+
+    #[test]
+    fn ping_pong_swaps_direction() {
+        let mut value = Animated::new(0.0, Duration::from_secs(1), Easing::Linear);
+        value.set_loop(LoopMode::PingPong);
+        value.set_target(10.0);
+
+        value.update(Duration::from_millis(1250));
+        assert!((value.current() - 7.5).abs() < 1e-5);
+        assert_eq!(value.target(), 0.0);
+
+        value.update(Duration::from_millis(1000));
+        assert!((value.current() - 2.5).abs() < 1e-5);
+        assert_eq!(value.target(), 10.0);
+
+        value.update(Duration::from_millis(2000));
+        assert!((value.current() - 2.5).abs() < 1e-5);
+        assert_eq!(value.target(), 10.0);
+        assert!(!value.complete());
+    }
+
+    #[test]
+    fn manager_runs_delayed_tween() {
+        let mut manager = AnimationManager::new();
+        let seen = Rc::new(Cell::new(0.0));
+        let seen_in_tween = Rc::clone(&seen);
+        let handle = manager.schedule(
+            Tween::new(0.0, 10.0, Duration::from_secs(1), Easing::Linear)
+                .with_delay(Duration::from_millis(500))
+                .on_update(Box::new(move |value| seen_in_tween.set(value))),
+        );
+
+        manager.update(Duration::from_millis(400));
+        assert_eq!(manager.value(handle), Some(0.0));
+
+        manager.update(Duration::from_millis(600));
+        assert!((seen.get() - 5.0).abs() < 1e-5);
+
+        manager.update(Duration::from_secs(1));
+        assert_eq!(seen.get(), 10.0);
+        assert!(!manager.is_active(handle));
+    }
+
+    #[test]
+    fn manager_runs_sequence_and_timers() {
+        let mut manager = AnimationManager::new();
+        let completed = Rc::new(Cell::new(0));
+        let first_done = Rc::clone(&completed);
+        let timer_done = Rc::clone(&completed);
+
+        let first = manager.schedule(
+            Tween::new(0.0, 1.0, Duration::from_secs(1), Easing::Linear)
+                .on_complete(Box::new(move || first_done.set(first_done.get() + 1))),
+        );
+        let second = manager.schedule_after(
+            first,
+            Tween::new(1.0, 0.0, Duration::from_secs(1), Easing::Linear),
+        );
+        manager.after(
+            Duration::from_millis(1500),
+            Box::new(move || timer_done.set(timer_done.get() + 10)),
+        );
+
+        manager.update(Duration::from_secs(1));
+        assert_eq!(completed.get(), 1);
+        assert_eq!(manager.value(second), Some(1.0));
+
+        manager.update(Duration::from_millis(500));
+        assert_eq!(completed.get(), 11);
+        assert!((manager.value(second).unwrap() - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn cancel_skips_completion() {
+        let mut manager = AnimationManager::new();
+        let fired = Rc::new(Cell::new(false));
+        let fired_in_timer = Rc::clone(&fired);
+        let timer = manager.after(
+            Duration::from_millis(10),
+            Box::new(move || fired_in_timer.set(true)),
+        );
+
+        assert!(manager.cancel(timer));
+        assert!(!manager.cancel(timer));
+        manager.update(Duration::from_secs(1));
+        assert!(!fired.get());
+    }
+
+    #[test]
+    fn sequence_carries_leftover_time() {
+        let mut manager = AnimationManager::new();
+        let first = manager.schedule(Tween::new(0.0, 1.0, Duration::from_secs(1), Easing::Linear));
+        let second = manager.schedule_after(
+            first,
+            Tween::new(0.0, 10.0, Duration::from_secs(1), Easing::Linear),
+        );
+        let third = manager.schedule_after(
+            second,
+            Tween::new(0.0, 10.0, Duration::from_secs(1), Easing::Linear),
+        );
+
+        manager.update(Duration::from_millis(1500));
+        assert!(!manager.is_active(first));
+        assert!((manager.value(second).unwrap() - 5.0).abs() < 1e-5);
+
+        manager.update(Duration::from_millis(250));
+        assert!((manager.value(second).unwrap() - 7.5).abs() < 1e-5);
+
+        manager.update(Duration::from_millis(1000));
+        assert!(!manager.is_active(second));
+        assert!((manager.value(third).unwrap() - 7.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn hold_tween_lasts_its_duration() {
+        let mut manager = AnimationManager::new();
+        let hold = manager.schedule(Tween::new(1.0, 1.0, Duration::from_secs(2), Easing::Linear));
+
+        manager.update(Duration::from_millis(16));
+        assert!(manager.is_active(hold));
+
+        manager.update(Duration::from_millis(1984));
+        assert!(!manager.is_active(hold));
+    }
+}
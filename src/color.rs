@@ -0,0 +1,189 @@
+//! Colour representations.
+//!
+//! Colours are authored in sRGB space. Shader inputs are expected in linear
+//! space, so convert colours with [`Normalized::to_linear`] before handing
+//! them to the GPU.
+
+/// Colour with channels in the range [0, 1].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Normalized {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Normalized {
+    /// Create a new normalized colour.
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Linearly interpolate each channel towards `other`, with `t` clamped to [0, 1].
+    pub fn lerp(&self, other: &Normalized, t: f32) -> Normalized {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: f32, to: f32| from + (to - from) * t;
+        Normalized {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
+
+    /// Convert an sRGB colour to linear space. Alpha is left untouched.
+    pub fn to_linear(&self) -> Normalized {
+        let channel = |c: f32| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Normalized::new(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+
+    /// Convert a linear colour to sRGB space. Alpha is left untouched.
+    pub fn to_srgb(&self) -> Normalized {
+        let channel = |c: f32| {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        Normalized::new(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+}
+
+impl From<Decimal> for Normalized {
+    fn from(colour: Decimal) -> Self {
+        let channel = |c: u8| f32::from(c) / 255.0;
+        Self {
+            r: channel(colour.r),
+            g: channel(colour.g),
+            b: channel(colour.b),
+            a: channel(colour.a),
+        }
+    }
+}
+
+impl From<Normalized> for [f32; 4] {
+    fn from(colour: Normalized) -> Self {
+        [colour.r, colour.g, colour.b, colour.a]
+    }
+}
+
+/// Colour with channels in the range [0, 255].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Decimal {
+    /// Create a new decimal colour.
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Parse a `#RRGGBB` or `#RRGGBBAA` string; the leading `#` is optional.
+    /// When the alpha channel is omitted the colour is fully opaque.
+    pub fn from_hex(s: &str) -> Option<Decimal> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        if !matches!(digits.len(), 6 | 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).ok();
+        let a = if digits.len() == 8 { channel(3)? } else { 255 };
+        Some(Decimal {
+            r: channel(0)?,
+            g: channel(1)?,
+            b: channel(2)?,
+            a,
+        })
+    }
+}
+
+impl From<Normalized> for Decimal {
+    fn from(colour: Normalized) -> Self {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self {
+            r: channel(colour.r),
+            g: channel(colour.g),
+            b: channel(colour.b),
+            a: channel(colour.a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerp_clamps_t() {
+        let black = Normalized::new(0.0, 0.0, 0.0, 1.0);
+        let white = Normalized::new(1.0, 1.0, 1.0, 0.0);
+
+        assert_eq!(black.lerp(&white, 0.5), Normalized::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(black.lerp(&white, -1.0), black);
+        assert_eq!(black.lerp(&white, 2.0), white);
+    }
+
+    #[test]
+    fn from_hex_without_alpha() {
+        let expected = Some(Decimal::new(0xab, 0xcd, 0xef, 255));
+        assert_eq!(Decimal::from_hex("#abcdef"), expected);
+        assert_eq!(Decimal::from_hex("ABCDEF"), expected);
+    }
+
+    #[test]
+    fn from_hex_with_alpha() {
+        let expected = Some(Decimal::new(0x12, 0x34, 0x56, 0x78));
+        assert_eq!(Decimal::from_hex("#12345678"), expected);
+        assert_eq!(Decimal::from_hex("12345678"), expected);
+    }
+
+    #[test]
+    fn from_hex_malformed() {
+        for s in [
+            "",
+            "#",
+            "#12345",
+            "#1234567",
+            "#123456789",
+            "#12345g",
+            "+12345",
+            "##123456",
+            "#12é456",
+        ] {
+            assert_eq!(Decimal::from_hex(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn decimal_normalized_round_trip() {
+        let colour = Decimal::new(0, 64, 128, 255);
+        assert_eq!(Decimal::from(Normalized::from(colour)), colour);
+    }
+
+    #[test]
+    fn mid_gray_linear_round_trip() {
+        let gray = Normalized::new(0.5, 0.5, 0.5, 0.5);
+        let linear = gray.to_linear();
+        assert!((linear.r - 0.214).abs() < 1e-3);
+        assert_eq!(linear.a, 0.5);
+
+        let back = linear.to_srgb();
+        for (a, b) in <[f32; 4]>::from(back)
+            .iter()
+            .zip(<[f32; 4]>::from(gray).iter())
+        {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Keyboard-driven focus traversal.
+
+/// A widget that can receive keyboard focus.
+pub trait Focusable {
+    /// Whether the widget currently accepts focus.
+    fn is_focusable(&self) -> bool;
+    /// Whether the widget currently has focus.
+    fn is_focused(&self) -> bool;
+    /// Set or clear the focused flag of the widget.
+    fn set_focused(&mut self, focused: bool);
+
+    /// Give focus to the widget.
+    fn focus(&mut self) {
+        self.set_focused(true);
+    }
+
+    /// Take focus away from the widget.
+    fn unfocus(&mut self) {
+        self.set_focused(false);
+    }
+}
+
+impl<T: Focusable + ?Sized> Focusable for Box<T> {
+    fn is_focusable(&self) -> bool {
+        (**self).is_focusable()
+    }
+
+    fn is_focused(&self) -> bool {
+        (**self).is_focused()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        (**self).set_focused(focused)
+    }
+
+    fn focus(&mut self) {
+        (**self).focus()
+    }
+
+    fn unfocus(&mut self) {
+        (**self).unfocus()
+    }
+}
+
+/// Ordered list of widget handles that focus moves through.
+///
+/// Handles are indices into the widget slice passed to the traversal methods.
+/// Widgets that are not focusable are skipped and focus wraps around at both ends.
+#[derive(Debug, Default, Clone)]
+pub struct FocusChain {
+    /// Widget handles in traversal order.
+    order: Vec<usize>,
+    /// Position in `order` of the focused widget.
+    current: Option<usize>,
+}
+
+impl FocusChain {
+    /// Create an empty focus chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a widget handle at the end of the traversal order.
+    pub fn push(&mut self, handle: usize) {
+        self.order.push(handle);
+    }
+
+    /// Handle of the currently focused widget, if any.
+    pub fn focused(&self) -> Option<usize> {
+        self.current.map(|pos| self.order[pos])
+    }
+
+    /// Move focus to the next focusable widget (Tab).
+    pub fn focus_next<W: Focusable>(&mut self, widgets: &mut [W]) -> Option<usize> {
+        self.step(widgets, true)
+    }
+
+    /// Move focus to the previous focusable widget (Shift+Tab).
+    pub fn focus_prev<W: Focusable>(&mut self, widgets: &mut [W]) -> Option<usize> {
+        self.step(widgets, false)
+    }
+
+    /// Walk the chain in the given direction until a focusable widget is found.
+    fn step<W: Focusable>(&mut self, widgets: &mut [W], forward: bool) -> Option<usize> {
+        let len = self.order.len();
+        if len == 0 {
+            return None;
+        }
+
+        let start = match self.current {
+            Some(pos) => pos,
+            None if forward => len - 1,
+            None => 0,
+        };
+
+        let next = (1..=len)
+            .map(|i| {
+                if forward {
+                    (start + i) % len
+                } else {
+                    (start + len - i) % len
+                }
+            })
+            .find(|&pos| {
+                widgets
+                    .get(self.order[pos])
+                    .is_some_and(|w| w.is_focusable())
+            });
+
+        if let Some(widget) = self.focused().and_then(|handle| widgets.get_mut(handle)) {
+            widget.unfocus();
+        }
+
+        self.current = next;
+        let handle = self.focused()?;
+        widgets[handle].focus();
+        Some(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Widget {
+        focusable: bool,
+        focused: bool,
+    }
+
+    impl Focusable for Widget {
+        fn is_focusable(&self) -> bool {
+            self.focusable
+        }
+
+        fn is_focused(&self) -> bool {
+            self.focused
+        }
+
+        fn set_focused(&mut self, focused: bool) {
+            self.focused = focused;
+        }
+    }
+
+    fn widgets(focusable: &[bool]) -> (Vec<Widget>, FocusChain) {
+        let mut chain = FocusChain::new();
+        let widgets = focusable
+            .iter()
+            .enumerate()
+            .map(|(i, &focusable)| {
+                chain.push(i);
+                Widget {
+                    focusable,
+                    focused: false,
+                }
+            })
+            .collect();
+        (widgets, chain)
+    }
+
+    #[test]
+    fn next_skips_unfocusable_and_wraps() {
+        let (mut widgets, mut chain) = widgets(&[true, false, true]);
+
+        assert_eq!(chain.focus_next(&mut widgets), Some(0));
+        assert_eq!(chain.focus_next(&mut widgets), Some(2));
+        assert!(!widgets[0].is_focused());
+        assert!(widgets[2].is_focused());
+        assert_eq!(chain.focus_next(&mut widgets), Some(0));
+        assert!(widgets[0].is_focused());
+        assert!(!widgets[2].is_focused());
+    }
+
+    #[test]
+    fn prev_starts_from_the_end() {
+        let (mut widgets, mut chain) = widgets(&[true, true, false]);
+
+        assert_eq!(chain.focus_prev(&mut widgets), Some(1));
+        assert_eq!(chain.focus_prev(&mut widgets), Some(0));
+        assert_eq!(chain.focus_prev(&mut widgets), Some(1));
+    }
+
+    #[test]
+    fn nothing_focusable() {
+        let (mut widgets, mut chain) = widgets(&[false, false]);
+
+        assert_eq!(chain.focus_next(&mut widgets), None);
+        assert_eq!(chain.focused(), None);
+    }
+}
@@ -0,0 +1,83 @@
+//! Geometric primitives shared by widgets.
+
+use cgmath::{Point2, Vector2};
+
+/// Axis-aligned rectangle in screen coordinates (y grows downwards).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    /// Top-left corner.
+    pub min: Point2<f32>,
+    /// Width and height.
+    pub size: Vector2<f32>,
+}
+
+impl Rect {
+    /// Create a new rectangle from its top-left corner and size.
+    pub fn new(min: Point2<f32>, size: Vector2<f32>) -> Self {
+        Self { min, size }
+    }
+
+    /// Bottom-right corner.
+    pub fn max(&self) -> Point2<f32> {
+        self.min + self.size
+    }
+
+    /// Whether `point` lies inside the rectangle.
+    /// The top and left edges are inside, the bottom and right edges are not,
+    /// so adjacent rectangles never both contain the same point.
+    pub fn contains(&self, point: Point2<f32>) -> bool {
+        let max = self.max();
+        point.x >= self.min.x && point.x < max.x && point.y >= self.min.y && point.y < max.y
+    }
+
+    /// Whether the two rectangles overlap over a non-empty area.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Overlapping area of the two rectangles, if it is not empty.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let (max, other_max) = (self.max(), other.max());
+        let min = Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point2::new(max.x.min(other_max.x), max.y.min(other_max.y));
+        (min.x < max.x && min.y < max.y).then(|| Rect::new(min, max - min))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect::new(Point2::new(x, y), Vector2::new(w, h))
+    }
+
+    #[test]
+    fn contains_is_half_open() {
+        let r = rect(10.0, 20.0, 30.0, 40.0);
+
+        assert!(r.contains(Point2::new(10.0, 20.0)));
+        assert!(r.contains(Point2::new(39.9, 59.9)));
+        assert!(!r.contains(Point2::new(40.0, 30.0)));
+        assert!(!r.contains(Point2::new(20.0, 60.0)));
+        assert!(!r.contains(Point2::new(9.9, 30.0)));
+    }
+
+    #[test]
+    fn intersection_of_overlapping_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, -5.0, 10.0, 10.0);
+
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&b), Some(rect(5.0, 0.0, 5.0, 5.0)));
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+    }
+
+    #[test]
+    fn touching_rects_do_not_intersect() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+
+        assert!(!a.intersects(&rect(10.0, 0.0, 5.0, 5.0)));
+        assert_eq!(a.intersection(&rect(20.0, 20.0, 5.0, 5.0)), None);
+    }
+}
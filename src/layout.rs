@@ -0,0 +1,132 @@
+//! Box layout containers.
+//!
+//! Containers only compute positions; the caller applies them to its widgets.
+
+use cgmath::{Point2, Vector2};
+
+use crate::geometry::Rect;
+
+/// Direction in which a box stacks its children.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Left to right.
+    #[default]
+    Horizontal,
+    /// Top to bottom.
+    Vertical,
+}
+
+/// Placement of children along the cross axis of a box.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Left edge for a vertical box, top edge for a horizontal one.
+    #[default]
+    Start,
+    /// Centered in the available space.
+    Center,
+    /// Right edge for a vertical box, bottom edge for a horizontal one.
+    End,
+}
+
+/// Stacks children one after the other along an axis.
+#[derive(Debug, Default, Clone)]
+pub struct BoxLayout {
+    /// Direction in which children are stacked.
+    pub axis: Axis,
+    /// Size of each child, in stacking order.
+    pub children: Vec<Vector2<f32>>,
+    /// Space between consecutive children.
+    pub spacing: f32,
+    /// Space between the area edges and the children.
+    pub padding: f32,
+    /// Placement of the children along the cross axis.
+    pub alignment: Alignment,
+}
+
+impl BoxLayout {
+    /// Create an empty box stacking along `axis`.
+    pub fn new(axis: Axis, spacing: f32, padding: f32, alignment: Alignment) -> Self {
+        Self {
+            axis,
+            children: Vec::new(),
+            spacing,
+            padding,
+            alignment,
+        }
+    }
+
+    /// Append a child with the given size.
+    pub fn push(&mut self, size: Vector2<f32>) {
+        self.children.push(size);
+    }
+
+    /// Top-left position of each child inside `area`.
+    pub fn layout(&self, area: Rect) -> Vec<Point2<f32>> {
+        // Work in (main, cross) coordinates and swap back at the end.
+        let vertical = self.axis == Axis::Vertical;
+        let swap = |v: Vector2<f32>| if vertical { Vector2::new(v.y, v.x) } else { v };
+        let origin = swap(Vector2::new(area.min.x, area.min.y));
+        let cross_space = swap(area.size).y - 2.0 * self.padding;
+
+        let mut main = origin.x + self.padding;
+        self.children
+            .iter()
+            .map(|&size| {
+                let size = swap(size);
+                let cross = origin.y
+                    + self.padding
+                    + match self.alignment {
+                        Alignment::Start => 0.0,
+                        Alignment::Center => (cross_space - size.y) / 2.0,
+                        Alignment::End => cross_space - size.y,
+                    };
+                let position = swap(Vector2::new(main, cross));
+                main += size.x + self.spacing;
+                Point2::new(position.x, position.y)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> Rect {
+        Rect::new(Point2::new(10.0, 20.0), Vector2::new(100.0, 200.0))
+    }
+
+    #[test]
+    fn vertical_stacks_downwards() {
+        let mut vbox = BoxLayout::new(Axis::Vertical, 5.0, 2.0, Alignment::Start);
+        vbox.push(Vector2::new(50.0, 10.0));
+        vbox.push(Vector2::new(30.0, 20.0));
+
+        assert_eq!(
+            vbox.layout(area()),
+            vec![Point2::new(12.0, 22.0), Point2::new(12.0, 37.0)]
+        );
+    }
+
+    #[test]
+    fn vertical_cross_alignment() {
+        let mut vbox = BoxLayout::new(Axis::Vertical, 0.0, 2.0, Alignment::Center);
+        vbox.push(Vector2::new(56.0, 10.0));
+        assert_eq!(vbox.layout(area()), vec![Point2::new(32.0, 22.0)]);
+
+        vbox.alignment = Alignment::End;
+        assert_eq!(vbox.layout(area()), vec![Point2::new(52.0, 22.0)]);
+    }
+
+    #[test]
+    fn horizontal_stacks_rightwards() {
+        let mut hbox = BoxLayout::new(Axis::Horizontal, 5.0, 0.0, Alignment::End);
+        hbox.push(Vector2::new(10.0, 50.0));
+        hbox.push(Vector2::new(20.0, 100.0));
+
+        assert_eq!(
+            hbox.layout(area()),
+            vec![Point2::new(10.0, 170.0), Point2::new(25.0, 120.0)]
+        );
+    }
+}
@@ -1,3 +1,9 @@
+pub mod animation;
+pub mod color;
+pub mod focus;
+pub mod geometry;
+pub mod layout;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }